@@ -0,0 +1,283 @@
+//! Owned, in-memory pixel buffers.
+//!
+//! [`Buffer`] is an allocation-light off-screen canvas: render into it once with the normal
+//! [`DrawTarget`] API, then draw the finished buffer onto a real display as an [`ImageDrawable`].
+//!
+//! [`DrawTarget`]: crate::draw_target::DrawTarget
+//! [`ImageDrawable`]: crate::image::ImageDrawable
+
+use core::marker::PhantomData;
+
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Point, Size},
+    image::ImageDrawable,
+    pixelcolor::{raw::RawData, GetPixel, PixelColor},
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// An owned, in-memory pixel buffer.
+///
+/// `Buffer<C, D>` packs pixels at the native bit depth of the color type `C`, using `C::Raw` for
+/// the packing: a `Buffer<BinaryColor, _>` stores 8 pixels per byte, a `Buffer<Gray2, _>` stores
+/// 4 pixels per byte, and so on up to whole-byte-per-pixel formats like `Rgb888`. Rows are packed
+/// MSB-first, with the length of each row rounded up to a whole number of bytes.
+///
+/// The backing storage `D` is generic over anything that can be viewed as a byte slice, so a
+/// `Buffer` can be backed by a borrowed `&mut [u8]`, a `heapless::Vec<u8, N>`, or an owned
+/// `Vec<u8>`, depending on what allocation strategy the target supports.
+pub struct Buffer<C, D> {
+    data: D,
+    size: Size,
+    color_type: PhantomData<C>,
+}
+
+impl<C, D> Buffer<C, D>
+where
+    C: PixelColor,
+    D: AsRef<[u8]>,
+{
+    /// Creates a new buffer of the given `size`, backed by `data`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is smaller than the number of bytes required to store a buffer of
+    /// `size`, see [`Self::data_len`].
+    pub fn new(data: D, size: Size) -> Self {
+        let buffer = Self {
+            data,
+            size,
+            color_type: PhantomData,
+        };
+
+        assert!(
+            buffer.data.as_ref().len() >= buffer.data_len(),
+            "data is too small to hold a {}x{} buffer",
+            size.width,
+            size.height
+        );
+
+        buffer
+    }
+
+    /// Returns the size of this buffer.
+    pub const fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the number of bytes required to store a buffer of this size.
+    pub fn data_len(&self) -> usize {
+        bytes_per_row::<C>(self.size.width) * self.size.height as usize
+    }
+
+    /// Returns the color of the pixel at `point`.
+    ///
+    /// Returns the color decoded from an all-zero raw value if `point` is outside the buffer.
+    pub fn pixel(&self, point: Point) -> C {
+        if !self.bounding_box().contains(point) {
+            return C::from(C::Raw::from_u32(0));
+        }
+
+        let raw = read_bits(
+            self.data.as_ref(),
+            bit_offset::<C>(self.size, point),
+            C::Raw::BITS_PER_PIXEL,
+        );
+
+        C::from(C::Raw::from_u32(raw))
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(Point::zero(), self.size)
+    }
+}
+
+impl<C, D> Buffer<C, D>
+where
+    C: PixelColor,
+    D: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Sets the color of the pixel at `point`.
+    ///
+    /// Does nothing if `point` is outside the buffer.
+    pub fn set_pixel(&mut self, point: Point, color: C) {
+        if !self.bounding_box().contains(point) {
+            return;
+        }
+
+        let offset = bit_offset::<C>(self.size, point);
+        let raw: C::Raw = color.into();
+
+        write_bits(self.data.as_mut(), offset, C::Raw::BITS_PER_PIXEL, raw.into_u32());
+    }
+}
+
+fn bytes_per_row<C: PixelColor>(width: u32) -> usize {
+    (width as usize * C::Raw::BITS_PER_PIXEL + 7) / 8
+}
+
+fn bit_offset<C: PixelColor>(size: Size, point: Point) -> usize {
+    bytes_per_row::<C>(size.width) * 8 * point.y as usize + point.x as usize * C::Raw::BITS_PER_PIXEL
+}
+
+/// Reads `bits` bits starting at `bit_offset` from `data`, MSB-first.
+fn read_bits(data: &[u8], bit_offset: usize, bits: usize) -> u32 {
+    let mut value = 0u32;
+
+    for i in 0..bits {
+        let index = bit_offset + i;
+        let bit = (data[index / 8] >> (7 - index % 8)) & 1;
+        value = (value << 1) | u32::from(bit);
+    }
+
+    value
+}
+
+/// Writes the least significant `bits` bits of `value` starting at `bit_offset` in `data`,
+/// MSB-first.
+fn write_bits(data: &mut [u8], bit_offset: usize, bits: usize, value: u32) {
+    for i in 0..bits {
+        let index = bit_offset + i;
+        let bit = (value >> (bits - 1 - i)) & 1 != 0;
+        let mask = 1 << (7 - index % 8);
+
+        if bit {
+            data[index / 8] |= mask;
+        } else {
+            data[index / 8] &= !mask;
+        }
+    }
+}
+
+impl<C, D> DrawTarget for Buffer<C, D>
+where
+    C: PixelColor,
+    D: AsRef<[u8]> + AsMut<[u8]>,
+{
+    type Color = C;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            self.set_pixel(point, color);
+        }
+
+        Ok(())
+    }
+}
+
+impl<C, D> OriginDimensions for Buffer<C, D>
+where
+    C: PixelColor,
+{
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<C, D> GetPixel for Buffer<C, D>
+where
+    C: PixelColor,
+    D: AsRef<[u8]> + AsMut<[u8]>,
+{
+    fn get_pixel(&self, point: Point) -> C {
+        self.pixel(point)
+    }
+}
+
+impl<C, D> ImageDrawable for Buffer<C, D>
+where
+    C: PixelColor,
+    D: AsRef<[u8]>,
+{
+    type Color = C;
+
+    fn draw<T>(&self, target: &mut T) -> Result<(), T::Error>
+    where
+        T: DrawTarget<Color = C>,
+    {
+        target.draw_iter(
+            self.bounding_box()
+                .points()
+                .map(|point| Pixel(point, self.pixel(point))),
+        )
+    }
+
+    fn draw_sub_image<T>(&self, target: &mut T, area: &Rectangle) -> Result<(), T::Error>
+    where
+        T: DrawTarget<Color = C>,
+    {
+        target.draw_iter(
+            area.intersection(&self.bounding_box())
+                .points()
+                .map(|point| Pixel(point, self.pixel(point))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::{BinaryColor, Gray2, Rgb565, RgbColor};
+
+    #[test]
+    fn out_of_bounds_pixel_returns_zero_value_color_instead_of_panicking() {
+        let mut data = [0u8; 2];
+        let buffer = Buffer::<BinaryColor, _>::new(&mut data[..], Size::new(8, 2));
+
+        assert_eq!(buffer.pixel(Point::new(100, 100)), BinaryColor::Off);
+        assert_eq!(buffer.pixel(Point::new(-1, 0)), BinaryColor::Off);
+        assert_eq!(buffer.pixel(Point::new(0, -1)), BinaryColor::Off);
+    }
+
+    #[test]
+    fn binary_color_packs_8_pixels_per_byte() {
+        let mut data = [0u8; 1];
+        let mut buffer = Buffer::<BinaryColor, _>::new(&mut data[..], Size::new(8, 1));
+
+        buffer.set_pixel(Point::new(0, 0), BinaryColor::On);
+        buffer.set_pixel(Point::new(7, 0), BinaryColor::On);
+
+        assert_eq!(data[0], 0b1000_0001);
+    }
+
+    #[test]
+    fn gray2_set_pixel_pixel_round_trip() {
+        let mut data = [0u8; 1];
+        let mut buffer = Buffer::<Gray2, _>::new(&mut data[..], Size::new(4, 1));
+
+        let colors = [Gray2::new(0), Gray2::new(1), Gray2::new(2), Gray2::new(3)];
+        for (x, color) in colors.iter().enumerate() {
+            buffer.set_pixel(Point::new(x as i32, 0), *color);
+        }
+
+        for (x, color) in colors.iter().enumerate() {
+            assert_eq!(buffer.pixel(Point::new(x as i32, 0)), *color);
+        }
+    }
+
+    #[test]
+    fn rgb565_set_pixel_pixel_round_trip() {
+        let mut data = [0u8; 2 * 2 * 2];
+        let mut buffer = Buffer::<Rgb565, _>::new(&mut data[..], Size::new(2, 2));
+
+        let color = Rgb565::new(0x1F, 0x00, 0x0A);
+        buffer.set_pixel(Point::new(1, 1), color);
+
+        assert_eq!(buffer.pixel(Point::new(1, 1)), color);
+        assert_eq!(buffer.pixel(Point::new(0, 0)), Rgb565::BLACK);
+    }
+
+    #[test]
+    fn data_len_rounds_row_up_to_whole_bytes() {
+        let mut data = [0u8; 4];
+        let buffer = Buffer::<BinaryColor, _>::new(&mut data[..], Size::new(5, 2));
+
+        // 5 pixels per row at 1 bit each needs 1 byte, times 2 rows.
+        assert_eq!(buffer.data_len(), 2);
+    }
+}
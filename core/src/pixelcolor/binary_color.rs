@@ -0,0 +1,78 @@
+use crate::pixelcolor::{raw::RawU1, PixelColor};
+
+/// Binary color.
+///
+/// `BinaryColor` is used for displays and images with two possible color states, for example
+/// monochrome OLED displays or e-paper displays that only support a single bit per pixel.
+///
+/// `BinaryColor` is convertible to and from `bool` via the [`is_on`]/[`is_off`] and [`from`]
+/// methods, or via `Into<bool>`/`From<bool>`.
+///
+/// [`is_on`]: BinaryColor::is_on
+/// [`is_off`]: BinaryColor::is_off
+/// [`from`]: BinaryColor::from
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum BinaryColor {
+    /// Off state.
+    #[default]
+    Off,
+    /// On state.
+    On,
+}
+
+impl BinaryColor {
+    /// Inverts this color.
+    pub const fn invert(self) -> Self {
+        match self {
+            Self::Off => Self::On,
+            Self::On => Self::Off,
+        }
+    }
+
+    /// Returns `true` if this color is on.
+    pub const fn is_on(self) -> bool {
+        matches!(self, Self::On)
+    }
+
+    /// Returns `true` if this color is off.
+    pub const fn is_off(self) -> bool {
+        matches!(self, Self::Off)
+    }
+
+    /// Returns `On` if `value` is `true` and `Off` if `value` is `false`.
+    pub const fn from(value: bool) -> Self {
+        if value {
+            Self::On
+        } else {
+            Self::Off
+        }
+    }
+}
+
+impl PixelColor for BinaryColor {
+    type Raw = RawU1;
+}
+
+impl From<bool> for BinaryColor {
+    fn from(value: bool) -> Self {
+        Self::from(value)
+    }
+}
+
+impl From<BinaryColor> for bool {
+    fn from(color: BinaryColor) -> Self {
+        color.is_on()
+    }
+}
+
+impl From<RawU1> for BinaryColor {
+    fn from(data: RawU1) -> Self {
+        Self::from(data.into_inner() != 0)
+    }
+}
+
+impl From<BinaryColor> for RawU1 {
+    fn from(color: BinaryColor) -> Self {
+        RawU1::new(color.is_on() as u8)
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Point, Size},
+    pixelcolor::{Rgb888, Rgba8888, RgbColor},
+    Pixel,
+};
+
+/// A [`DrawTarget`] that additionally allows reading back the color of a single pixel.
+///
+/// This is a minimal readback capability, required by [`Blend`] to implement source-over
+/// compositing: blending a translucent pixel onto a target requires knowing the color that is
+/// already there.
+pub trait GetPixel: DrawTarget {
+    /// Returns the color of the pixel at `point`.
+    ///
+    /// The result is unspecified if `point` is outside of the bounding box returned by
+    /// [`Dimensions::bounding_box`](crate::geometry::Dimensions::bounding_box).
+    fn get_pixel(&self, point: Point) -> Self::Color;
+}
+
+/// Source-over alpha compositing adapter.
+///
+/// `Blend` wraps an opaque [`Rgb888`] draw target and exposes it as a [`DrawTarget`] for
+/// [`Rgba8888`] pixels. Each drawn pixel is blended onto the color already present at that
+/// location using the "source-over" formula `out = src * a + dst * (1 - a)`, applied
+/// independently to each channel using straight (non-premultiplied) alpha.
+///
+/// Use [`RgbaBlendExt::blended`] to wrap an existing target.
+pub struct Blend<'a, T> {
+    parent: &'a mut T,
+}
+
+impl<'a, T> Blend<'a, T>
+where
+    T: GetPixel<Color = Rgb888>,
+{
+    /// Wraps `parent` in a compositing adapter.
+    pub fn new(parent: &'a mut T) -> Self {
+        Self { parent }
+    }
+}
+
+impl<T> DrawTarget for Blend<'_, T>
+where
+    T: GetPixel<Color = Rgb888>,
+{
+    type Color = Rgba8888;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            let dst = self.parent.get_pixel(point);
+            let blended = blend_over(color, dst);
+            self.parent.draw_iter(core::iter::once(Pixel(point, blended)))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> OriginDimensions for Blend<'_, T>
+where
+    T: GetPixel<Color = Rgb888> + OriginDimensions,
+{
+    fn size(&self) -> Size {
+        self.parent.size()
+    }
+}
+
+/// Extension trait adding [`blended`](RgbaBlendExt::blended) to opaque [`Rgb888`] draw targets
+/// that support pixel readback.
+pub trait RgbaBlendExt: GetPixel<Color = Rgb888> + Sized {
+    /// Wraps this draw target in a [`Blend`] adapter, allowing [`Rgba8888`] pixels to be drawn
+    /// onto it using source-over alpha compositing.
+    fn blended(&mut self) -> Blend<'_, Self> {
+        Blend::new(self)
+    }
+}
+
+impl<T: GetPixel<Color = Rgb888>> RgbaBlendExt for T {}
+
+/// Blends `src` onto `dst` using the source-over formula, with straight (non-premultiplied)
+/// alpha.
+fn blend_over(src: Rgba8888, dst: Rgb888) -> Rgb888 {
+    let a = src.a();
+
+    Rgb888::new(
+        blend_channel(src.r(), dst.r(), a),
+        blend_channel(src.g(), dst.g(), a),
+        blend_channel(src.b(), dst.b(), a),
+    )
+}
+
+/// Blends a single channel: `(src * a + dst * (255 - a)) / 255`, rounded to the nearest integer.
+fn blend_channel(src: u8, dst: u8, alpha: u8) -> u8 {
+    let src = src as u32;
+    let dst = dst as u32;
+    let alpha = alpha as u32;
+
+    ((src * alpha + dst * (255 - alpha) + 127) / 255) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::Buffer;
+
+    #[test]
+    fn blend_channel_keeps_destination_when_alpha_is_zero() {
+        assert_eq!(blend_channel(200, 50, 0), 50);
+    }
+
+    #[test]
+    fn blend_channel_uses_source_when_alpha_is_full() {
+        assert_eq!(blend_channel(200, 50, 255), 200);
+    }
+
+    #[test]
+    fn blend_channel_rounds_mid_range_blend_to_nearest() {
+        assert_eq!(blend_channel(255, 0, 128), 128);
+        assert_eq!(blend_channel(0, 255, 128), 127);
+    }
+
+    #[test]
+    fn blend_over_blends_each_channel_independently() {
+        let src = Rgba8888::new(255, 0, 0, 128);
+        let dst = Rgb888::new(0, 0, 0);
+
+        assert_eq!(blend_over(src, dst), Rgb888::new(128, 0, 0));
+    }
+
+    #[test]
+    fn blend_draw_iter_reads_blends_and_writes_back_through_get_pixel() {
+        let mut data = [0u8; 3];
+        let mut target = Buffer::<Rgb888, _>::new(&mut data[..], Size::new(1, 1));
+
+        target
+            .blended()
+            .draw_iter(core::iter::once(Pixel(
+                Point::zero(),
+                Rgba8888::new(255, 0, 0, 128),
+            )))
+            .unwrap();
+
+        assert_eq!(target.pixel(Point::zero()), Rgb888::new(128, 0, 0));
+    }
+}
@@ -0,0 +1,20 @@
+use crate::pixelcolor::{
+    BinaryColor, Bgr565, Bgr888, Gray2, Gray4, Gray8, GrayColor, Rgb565, Rgb888, RgbColor,
+};
+
+macro_rules! from_binary_color {
+    ($($color:ty),+ $(,)?) => {
+        $(
+            impl From<BinaryColor> for $color {
+                fn from(color: BinaryColor) -> Self {
+                    match color {
+                        BinaryColor::Off => Self::BLACK,
+                        BinaryColor::On => Self::WHITE,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+from_binary_color!(Rgb565, Rgb888, Bgr565, Bgr888, Gray2, Gray4, Gray8);
@@ -0,0 +1,192 @@
+use crate::pixelcolor::{
+    raw::{RawU2, RawU4, RawU8},
+    PixelColor,
+};
+
+/// Grayscale color.
+///
+/// `GrayColor` is implemented by all grayscale color types and provides access to the luma
+/// value, as well as the `BLACK` and `WHITE` shades that are common to all bit depths.
+pub trait GrayColor {
+    /// Luma value, using the full range of the underlying storage type (`0..=2^depth - 1`).
+    fn luma(&self) -> u8;
+
+    /// Black.
+    const BLACK: Self;
+
+    /// White.
+    const WHITE: Self;
+}
+
+macro_rules! gray_color {
+    ($(#[$meta:meta])* $name:ident, $raw:ty, $bpp:expr) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+        pub struct $name(u8);
+
+        impl $name {
+            /// Creates a new color from a luma value.
+            ///
+            /// Only the least significant bits specified by this type's bit depth are used,
+            /// any other bits are masked out.
+            pub const fn new(luma: u8) -> Self {
+                Self(luma & ((1 << $bpp) - 1))
+            }
+        }
+
+        impl GrayColor for $name {
+            fn luma(&self) -> u8 {
+                self.0
+            }
+
+            const BLACK: Self = Self::new(0);
+            const WHITE: Self = Self::new((1 << $bpp) - 1);
+        }
+
+        impl PixelColor for $name {
+            type Raw = $raw;
+        }
+
+        impl From<$raw> for $name {
+            fn from(data: $raw) -> Self {
+                Self::new(data.into_inner())
+            }
+        }
+
+        impl From<$name> for $raw {
+            fn from(color: $name) -> Self {
+                Self::new(color.0)
+            }
+        }
+    };
+}
+
+gray_color!(
+    /// 2 bit grayscale color, with four shades from black to white.
+    ///
+    /// The four shades correspond to the `Black`/`DarkGray`/`Gray`/`White` levels used by 2bpp
+    /// e-paper panels, with [`Self::BLACK`] encoded as `0b00` and [`Self::WHITE`] as `0b11`. See
+    /// [`Self::bit_code`] and [`Self::repeated_byte`] for converting to the native panel
+    /// encoding, and [`Self::pack_scanline`] for packing a whole row at once.
+    Gray2,
+    RawU2,
+    2
+);
+
+impl Gray2 {
+    /// Returns this color's 2-bit panel code, in the range `0..=3`.
+    pub const fn bit_code(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the byte formed by tiling this color's 2-bit code four times.
+    ///
+    /// This is the fastest way to fill a run of pixels with a single [`Gray2`] shade, since e-paper
+    /// drivers can write this byte directly instead of packing pixels one at a time: `BLACK` is
+    /// `0x00`, `DarkGray`-equivalent `0b01` is `0x55`, `Gray`-equivalent `0b10` is `0xAA`, and
+    /// `WHITE` is `0xFF`.
+    pub const fn repeated_byte(self) -> u8 {
+        let code = self.bit_code();
+        code | code << 2 | code << 4 | code << 6
+    }
+
+    /// Packs `pixels` into `out` as MSB-first 2-bits-per-pixel bytes, 4 pixels per byte.
+    ///
+    /// Returns the number of bytes written. Pixels beyond `out.len() * 4` are dropped; if the
+    /// number of pixels isn't a multiple of 4, the unused low bits of the last byte are left at
+    /// `0`.
+    pub fn pack_scanline<I>(pixels: I, out: &mut [u8]) -> usize
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let mut len = 0;
+
+        for (i, pixel) in pixels.into_iter().enumerate() {
+            let byte_index = i / 4;
+            if byte_index >= out.len() {
+                break;
+            }
+
+            if i % 4 == 0 {
+                out[byte_index] = 0;
+            }
+
+            let shift = 6 - (i % 4) * 2;
+            out[byte_index] |= pixel.bit_code() << shift;
+            len = byte_index + 1;
+        }
+
+        len
+    }
+}
+
+gray_color!(
+    /// 4 bit grayscale color, with 16 shades from black to white.
+    Gray4,
+    RawU4,
+    4
+);
+gray_color!(
+    /// 8 bit grayscale color, with 256 shades from black to white.
+    Gray8,
+    RawU8,
+    8
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_code_matches_luma() {
+        for luma in 0..=3 {
+            assert_eq!(Gray2::new(luma).bit_code(), luma);
+        }
+    }
+
+    #[test]
+    fn repeated_byte_tiles_the_2_bit_code() {
+        assert_eq!(Gray2::BLACK.repeated_byte(), 0x00);
+        assert_eq!(Gray2::new(0b01).repeated_byte(), 0x55);
+        assert_eq!(Gray2::new(0b10).repeated_byte(), 0xAA);
+        assert_eq!(Gray2::WHITE.repeated_byte(), 0xFF);
+    }
+
+    #[test]
+    fn pack_scanline_packs_4_pixels_per_byte_msb_first() {
+        let pixels = [
+            Gray2::new(0b01),
+            Gray2::new(0b10),
+            Gray2::new(0b11),
+            Gray2::new(0b00),
+        ];
+        let mut out = [0u8; 1];
+
+        let written = Gray2::pack_scanline(pixels, &mut out);
+
+        assert_eq!(written, 1);
+        assert_eq!(out[0], 0b01_10_11_00);
+    }
+
+    #[test]
+    fn pack_scanline_leaves_trailing_bits_zero_for_a_partial_byte() {
+        let pixels = [Gray2::new(0b11), Gray2::new(0b11)];
+        let mut out = [0u8; 1];
+
+        let written = Gray2::pack_scanline(pixels, &mut out);
+
+        assert_eq!(written, 1);
+        assert_eq!(out[0], 0b11_11_00_00);
+    }
+
+    #[test]
+    fn pack_scanline_drops_pixels_that_dont_fit_in_out() {
+        let pixels = [Gray2::new(0b01); 8];
+        let mut out = [0u8; 1];
+
+        let written = Gray2::pack_scanline(pixels, &mut out);
+
+        assert_eq!(written, 1);
+        assert_eq!(out[0], 0b01_01_01_01);
+    }
+}
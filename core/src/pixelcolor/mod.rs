@@ -107,16 +107,20 @@
 //! [`raw` module]: raw
 
 mod binary_color;
+mod blend;
 mod conversion;
 mod gray_color;
 pub mod raw;
 mod rgb_color;
+mod rgba_color;
 mod web_colors;
 
 pub use binary_color::*;
+pub use blend::{Blend, GetPixel, RgbaBlendExt};
 pub use gray_color::*;
 use raw::RawData;
 pub use rgb_color::*;
+pub use rgba_color::{Argb8888, Rgba8888};
 pub use web_colors::WebColors;
 
 /// Pixel color trait.
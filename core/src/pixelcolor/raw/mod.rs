@@ -0,0 +1,146 @@
+//! Raw data types.
+//!
+//! Types in this module are used to represent the raw data of colors, as used for example in
+//! images or in the framebuffer of a display. The [`RawData`] trait is implemented for every
+//! raw data type used by the built-in color types, from [`RawU1`] (1 bit per pixel) up to
+//! [`RawU32`] (32 bits per pixel).
+//!
+//! Most users of this crate won't need to use the types in this module directly. They are
+//! primarily used when implementing a custom [`PixelColor`] or when converting between a color
+//! and the raw storage format used by a display driver.
+//!
+//! [`PixelColor`]: super::PixelColor
+
+use core::fmt;
+
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "bytemuck")]
+pub use pod::{cast_slice, cast_slice_mut, to_byte_order_mut, ByteOrder, SwapBytes};
+
+/// Raw data trait.
+///
+/// This trait is implemented by the raw storage types that are used to represent a [`PixelColor`]
+/// in its underlying binary form.
+///
+/// [`PixelColor`]: super::PixelColor
+pub trait RawData: Copy + PartialEq + Sized {
+    /// The underlying storage type, for example `u8`, `u16` or `u32`.
+    type Storage: Copy;
+
+    /// The number of bits used to store a single pixel.
+    const BITS_PER_PIXEL: usize;
+
+    /// Returns the underlying storage value.
+    fn into_inner(self) -> Self::Storage;
+
+    /// Creates a new raw data instance from its least significant `BITS_PER_PIXEL` bits.
+    fn from_u32(value: u32) -> Self;
+
+    /// Returns the underlying storage value, widened to a `u32`.
+    fn into_u32(self) -> u32;
+}
+
+macro_rules! raw_data {
+    ($(#[$meta:meta])* $name:ident, $storage:ty, $mask:expr, $bits:expr) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+        #[repr(transparent)]
+        pub struct $name($storage);
+
+        impl $name {
+            /// Creates a new raw data instance.
+            ///
+            /// Only the bits specified by this type's bit depth are significant, any other
+            /// bits in `value` are masked out.
+            pub const fn new(value: $storage) -> Self {
+                Self(value & $mask)
+            }
+
+            /// Returns the underlying storage value.
+            pub const fn into_inner(self) -> $storage {
+                self.0
+            }
+        }
+
+        impl RawData for $name {
+            type Storage = $storage;
+
+            const BITS_PER_PIXEL: usize = $bits;
+
+            fn into_inner(self) -> Self::Storage {
+                self.0
+            }
+
+            fn from_u32(value: u32) -> Self {
+                Self::new(value as $storage)
+            }
+
+            fn into_u32(self) -> u32 {
+                self.0 as u32
+            }
+        }
+
+        impl From<$storage> for $name {
+            fn from(value: $storage) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.0).finish()
+            }
+        }
+    };
+}
+
+raw_data!(
+    /// Raw storage type for 1 bit per pixel colors.
+    RawU1,
+    u8,
+    0x01,
+    1
+);
+raw_data!(
+    /// Raw storage type for 2 bits per pixel colors.
+    RawU2,
+    u8,
+    0x03,
+    2
+);
+raw_data!(
+    /// Raw storage type for 4 bits per pixel colors.
+    RawU4,
+    u8,
+    0x0F,
+    4
+);
+raw_data!(
+    /// Raw storage type for 8 bits per pixel colors.
+    RawU8,
+    u8,
+    0xFF,
+    8
+);
+raw_data!(
+    /// Raw storage type for 16 bits per pixel colors.
+    RawU16,
+    u16,
+    0xFFFF,
+    16
+);
+raw_data!(
+    /// Raw storage type for 24 bits per pixel colors.
+    RawU24,
+    u32,
+    0x00FF_FFFF,
+    24
+);
+raw_data!(
+    /// Raw storage type for 32 bits per pixel colors.
+    RawU32,
+    u32,
+    0xFFFF_FFFF,
+    32
+);
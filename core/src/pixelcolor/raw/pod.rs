@@ -0,0 +1,142 @@
+//! Zero-copy conversions between raw color storage and byte slices.
+//!
+//! Enabled by the `bytemuck` feature. Marks the fixed-width raw storage types ([`RawU8`],
+//! [`RawU16`] and [`RawU32`]) as [`bytemuck::Pod`], and adds [`cast_slice`]/[`cast_slice_mut`] to
+//! reinterpret a slice of them as bytes without copying. This turns flushing a whole framebuffer
+//! into a single `write()` of a reinterpreted slice, instead of converting one pixel at a time
+//! with [`IntoStorage`](crate::pixelcolor::IntoStorage).
+//!
+//! Multi-byte types additionally need an explicit [`ByteOrder`], since the in-memory order of a
+//! `u16`/`u32` depends on the host's endianness, but the wire format expected by a display does
+//! not. Use [`to_byte_order_mut`] to put a buffer into the order the display expects before
+//! casting it to bytes.
+//!
+//! 24-bit raw storage ([`RawU24`](super::RawU24)) is intentionally not included: its `u32`
+//! backing field has a padding byte that doesn't correspond to a byte on the wire, so it can't
+//! be reinterpreted as a `[u8]` of the expected length.
+//!
+//! [`cast_slice`]/[`cast_slice_mut`] only accept raw storage types, not [`PixelColor`] types like
+//! [`Rgb565`](crate::pixelcolor::Rgb565) directly: a color's fields are unpacked `u8` channels for
+//! convenient access, which doesn't match the bit-packed layout a display expects on the wire.
+//! Bulk-converting a color buffer still means converting each color with
+//! [`Into`]/[`IntoStorage`](crate::pixelcolor::IntoStorage) into its raw form first (for example
+//! collecting a `&[Rgb565]` into a `[RawU16; N]`); `cast_slice` then turns that already-packed
+//! buffer into bytes in a single zero-copy step, rather than converting one pixel directly to
+//! bytes at a time.
+
+use super::{RawU16, RawU32, RawU8};
+use bytemuck::{Pod, Zeroable};
+
+unsafe impl Zeroable for RawU8 {}
+unsafe impl Pod for RawU8 {}
+
+unsafe impl Zeroable for RawU16 {}
+unsafe impl Pod for RawU16 {}
+
+unsafe impl Zeroable for RawU32 {}
+unsafe impl Pod for RawU32 {}
+
+/// Byte order used when reinterpreting multi-byte raw storage as bytes.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ByteOrder {
+    /// Most significant byte first, as expected by most SPI/parallel displays.
+    BigEndian,
+    /// Least significant byte first.
+    LittleEndian,
+}
+
+/// Raw storage types whose multi-byte representation can be byte-swapped.
+pub trait SwapBytes: Copy {
+    /// Returns `self` with its byte order reversed.
+    fn swap_bytes(self) -> Self;
+}
+
+impl SwapBytes for RawU16 {
+    fn swap_bytes(self) -> Self {
+        Self::new(self.into_inner().swap_bytes())
+    }
+}
+
+impl SwapBytes for RawU32 {
+    fn swap_bytes(self) -> Self {
+        Self::new(self.into_inner().swap_bytes())
+    }
+}
+
+/// Puts every element of `raw` into the given [`ByteOrder`], swapping bytes in place if needed.
+///
+/// Call this before [`cast_slice`] or [`cast_slice_mut`] when the destination expects a specific
+/// byte order that may not match the host's native order.
+pub fn to_byte_order_mut<R: SwapBytes>(raw: &mut [R], order: ByteOrder) {
+    let host_is_little_endian = cfg!(target_endian = "little");
+    let order_is_little_endian = order == ByteOrder::LittleEndian;
+
+    if host_is_little_endian != order_is_little_endian {
+        for value in raw {
+            *value = value.swap_bytes();
+        }
+    }
+}
+
+/// Reinterprets a slice of [`Pod`] raw storage values as a slice of bytes, without copying.
+///
+/// The byte order of the result is the host's native order. Use [`to_byte_order_mut`] first if
+/// `raw` needs to be read back in a specific [`ByteOrder`].
+pub fn cast_slice<R: Pod>(raw: &[R]) -> &[u8] {
+    bytemuck::cast_slice(raw)
+}
+
+/// Reinterprets a mutable slice of [`Pod`] raw storage values as a mutable slice of bytes,
+/// without copying.
+pub fn cast_slice_mut<R: Pod>(raw: &mut [R]) -> &mut [u8] {
+    bytemuck::cast_slice_mut(raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swap_bytes_reverses_u16_byte_order() {
+        assert_eq!(RawU16::new(0x1234).swap_bytes().into_inner(), 0x3412);
+    }
+
+    #[test]
+    fn swap_bytes_reverses_u32_byte_order() {
+        assert_eq!(RawU32::new(0x1122_3344).swap_bytes().into_inner(), 0x4433_2211);
+    }
+
+    #[test]
+    fn to_byte_order_mut_produces_big_endian_bytes() {
+        let mut raw = [RawU16::new(0x1234)];
+
+        to_byte_order_mut(&mut raw, ByteOrder::BigEndian);
+
+        assert_eq!(cast_slice(&raw), 0x1234u16.to_be_bytes());
+    }
+
+    #[test]
+    fn to_byte_order_mut_produces_little_endian_bytes() {
+        let mut raw = [RawU16::new(0x1234)];
+
+        to_byte_order_mut(&mut raw, ByteOrder::LittleEndian);
+
+        assert_eq!(cast_slice(&raw), 0x1234u16.to_le_bytes());
+    }
+
+    #[test]
+    fn cast_slice_reinterprets_raw_u8_storage_as_bytes() {
+        let raw = [RawU8::new(1), RawU8::new(2), RawU8::new(3)];
+
+        assert_eq!(cast_slice(&raw), [1, 2, 3]);
+    }
+
+    #[test]
+    fn cast_slice_mut_allows_writing_through_to_the_raw_storage() {
+        let mut raw = [RawU8::new(0)];
+
+        cast_slice_mut(&mut raw)[0] = 42;
+
+        assert_eq!(raw[0].into_inner(), 42);
+    }
+}
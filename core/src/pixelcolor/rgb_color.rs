@@ -0,0 +1,259 @@
+use crate::pixelcolor::{
+    raw::{RawU16, RawU24},
+    PixelColor,
+};
+
+/// RGB color.
+///
+/// `RgbColor` is implemented by all built-in RGB color types and provides read access to the
+/// individual color channels as well as a set of commonly used colors.
+pub trait RgbColor: PixelColor {
+    /// Creates a new color.
+    ///
+    /// The `r`, `g` and `b` arguments are truncated to this color's channel bit depths.
+    fn new(r: u8, g: u8, b: u8) -> Self;
+
+    /// Maximum value of the red channel.
+    const MAX_R: u8;
+
+    /// Maximum value of the green channel.
+    const MAX_G: u8;
+
+    /// Maximum value of the blue channel.
+    const MAX_B: u8;
+
+    /// Returns the red channel value.
+    fn r(&self) -> u8;
+
+    /// Returns the green channel value.
+    fn g(&self) -> u8;
+
+    /// Returns the blue channel value.
+    fn b(&self) -> u8;
+
+    /// Black.
+    const BLACK: Self;
+
+    /// Red.
+    const RED: Self;
+
+    /// Green.
+    const GREEN: Self;
+
+    /// Blue.
+    const BLUE: Self;
+
+    /// Yellow.
+    const YELLOW: Self;
+
+    /// Magenta.
+    const MAGENTA: Self;
+
+    /// Cyan.
+    const CYAN: Self;
+
+    /// White.
+    const WHITE: Self;
+}
+
+/// Implements an RGB color type that stores its channels in the given bit order.
+///
+/// The logical channel order, as observed through [`RgbColor::r`]/[`g`]/[`b`] and the
+/// constructor, is always red-green-blue. The `$r_shift`/`$g_shift`/`$b_shift` parameters only
+/// control how the channels are packed into the type's [`PixelColor::Raw`] representation,
+/// which allows the same macro to produce both the RGB- and BGR-ordered color types.
+///
+/// [`g`]: RgbColor::g
+/// [`b`]: RgbColor::b
+macro_rules! rgb_color {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $raw:ty, $storage:ty,
+        ($r_bits:expr, $g_bits:expr, $b_bits:expr),
+        ($r_shift:expr, $g_shift:expr, $b_shift:expr)
+    ) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+        pub struct $name {
+            r: u8,
+            g: u8,
+            b: u8,
+        }
+
+        impl $name {
+            /// Creates a new color.
+            ///
+            /// The `r`, `g` and `b` arguments are truncated to this color's channel bit depths.
+            pub const fn new(r: u8, g: u8, b: u8) -> Self {
+                Self {
+                    r: r & Self::MAX_R,
+                    g: g & Self::MAX_G,
+                    b: b & Self::MAX_B,
+                }
+            }
+        }
+
+        impl RgbColor for $name {
+            fn new(r: u8, g: u8, b: u8) -> Self {
+                Self::new(r, g, b)
+            }
+
+            const MAX_R: u8 = (1 << $r_bits) - 1;
+            const MAX_G: u8 = (1 << $g_bits) - 1;
+            const MAX_B: u8 = (1 << $b_bits) - 1;
+
+            fn r(&self) -> u8 {
+                self.r
+            }
+
+            fn g(&self) -> u8 {
+                self.g
+            }
+
+            fn b(&self) -> u8 {
+                self.b
+            }
+
+            const BLACK: Self = Self::new(0, 0, 0);
+            const RED: Self = Self::new(Self::MAX_R, 0, 0);
+            const GREEN: Self = Self::new(0, Self::MAX_G, 0);
+            const BLUE: Self = Self::new(0, 0, Self::MAX_B);
+            const YELLOW: Self = Self::new(Self::MAX_R, Self::MAX_G, 0);
+            const MAGENTA: Self = Self::new(Self::MAX_R, 0, Self::MAX_B);
+            const CYAN: Self = Self::new(0, Self::MAX_G, Self::MAX_B);
+            const WHITE: Self = Self::new(Self::MAX_R, Self::MAX_G, Self::MAX_B);
+        }
+
+        impl PixelColor for $name {
+            type Raw = $raw;
+        }
+
+        impl From<$raw> for $name {
+            fn from(data: $raw) -> Self {
+                let p = data.into_inner() as $storage;
+
+                Self::new(
+                    (p >> $r_shift) as u8 & Self::MAX_R,
+                    (p >> $g_shift) as u8 & Self::MAX_G,
+                    (p >> $b_shift) as u8 & Self::MAX_B,
+                )
+            }
+        }
+
+        impl From<$name> for $raw {
+            fn from(color: $name) -> Self {
+                <$raw>::new(
+                    (color.r as $storage) << $r_shift
+                        | (color.g as $storage) << $g_shift
+                        | (color.b as $storage) << $b_shift,
+                )
+            }
+        }
+    };
+}
+
+rgb_color!(
+    /// RGB color with 5 bits for the red channel, 6 bits for the green channel, and 5 bits for
+    /// the blue channel, packed R-G-B into a 16 bit storage value.
+    Rgb565,
+    RawU16,
+    u16,
+    (5, 6, 5),
+    (11, 5, 0)
+);
+
+rgb_color!(
+    /// RGB color with 8 bits per channel, packed R-G-B into a 24 bit storage value.
+    Rgb888,
+    RawU24,
+    u32,
+    (8, 8, 8),
+    (16, 8, 0)
+);
+
+rgb_color!(
+    /// RGB color with 5 bits for the red channel, 6 bits for the green channel, and 5 bits for
+    /// the blue channel, packed B-G-R into a 16 bit storage value.
+    ///
+    /// This is the bit layout used by displays that wire up their blue and red channels in the
+    /// opposite order to [`Rgb565`], such as some ST7735/ILI9341 panels configured for BGR mode.
+    Bgr565,
+    RawU16,
+    u16,
+    (5, 6, 5),
+    (0, 5, 11)
+);
+
+rgb_color!(
+    /// RGB color with 8 bits per channel, packed B-G-R into a 24 bit storage value.
+    ///
+    /// This is the bit layout used by displays that wire up their blue and red channels in the
+    /// opposite order to [`Rgb888`].
+    Bgr888,
+    RawU24,
+    u32,
+    (8, 8, 8),
+    (0, 8, 16)
+);
+
+impl From<Rgb565> for Bgr565 {
+    fn from(color: Rgb565) -> Self {
+        Self::new(color.r(), color.g(), color.b())
+    }
+}
+
+impl From<Bgr565> for Rgb565 {
+    fn from(color: Bgr565) -> Self {
+        Self::new(color.r(), color.g(), color.b())
+    }
+}
+
+impl From<Rgb888> for Bgr888 {
+    fn from(color: Rgb888) -> Self {
+        Self::new(color.r(), color.g(), color.b())
+    }
+}
+
+impl From<Bgr888> for Rgb888 {
+    fn from(color: Bgr888) -> Self {
+        Self::new(color.r(), color.g(), color.b())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::IntoStorage;
+
+    #[test]
+    fn bgr888_packs_channels_in_reverse_byte_order_from_rgb888() {
+        assert_eq!(Rgb888::new(0x11, 0x22, 0x33).into_storage(), 0x11_22_33);
+        assert_eq!(Bgr888::new(0x11, 0x22, 0x33).into_storage(), 0x33_22_11);
+    }
+
+    #[test]
+    fn bgr565_packs_red_and_blue_in_reverse_bit_position_from_rgb565() {
+        assert_eq!(Rgb565::new(0x1F, 0, 0).into_storage(), 0x1F << 11);
+        assert_eq!(Bgr565::new(0x1F, 0, 0).into_storage(), 0x1F);
+        assert_eq!(Rgb565::new(0, 0, 0x1F).into_storage(), 0x1F);
+        assert_eq!(Bgr565::new(0, 0, 0x1F).into_storage(), 0x1F << 11);
+    }
+
+    #[test]
+    fn rgb565_bgr565_conversion_round_trips_logical_channels() {
+        let rgb = Rgb565::new(0x1F, 0x20, 0x0A);
+        let bgr: Bgr565 = rgb.into();
+
+        assert_eq!((bgr.r(), bgr.g(), bgr.b()), (rgb.r(), rgb.g(), rgb.b()));
+        assert_eq!(Rgb565::from(bgr), rgb);
+    }
+
+    #[test]
+    fn rgb888_bgr888_conversion_round_trips_logical_channels() {
+        let rgb = Rgb888::new(0x11, 0x22, 0x33);
+        let bgr: Bgr888 = rgb.into();
+
+        assert_eq!((bgr.r(), bgr.g(), bgr.b()), (rgb.r(), rgb.g(), rgb.b()));
+        assert_eq!(Rgb888::from(bgr), rgb);
+    }
+}
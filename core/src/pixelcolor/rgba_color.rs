@@ -0,0 +1,117 @@
+use crate::pixelcolor::{raw::RawU32, PixelColor, Rgb888};
+
+/// Implements an RGBA color type with 8 bits per channel, packed into a 32 bit storage value at
+/// the given bit-shifts.
+///
+/// This mirrors the [`rgb_color!`](super::rgb_color) idiom used for the opaque color types:
+/// the same fields and methods are generated for every instantiation, and only the shifts used
+/// to pack the channels into [`RawU32`] differ.
+macro_rules! rgba_color {
+    ($(#[$meta:meta])* $name:ident, ($r_shift:expr, $g_shift:expr, $b_shift:expr, $a_shift:expr)) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+        pub struct $name {
+            r: u8,
+            g: u8,
+            b: u8,
+            a: u8,
+        }
+
+        impl $name {
+            /// Creates a new color.
+            pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+                Self { r, g, b, a }
+            }
+
+            /// Returns the red channel value.
+            pub const fn r(self) -> u8 {
+                self.r
+            }
+
+            /// Returns the green channel value.
+            pub const fn g(self) -> u8 {
+                self.g
+            }
+
+            /// Returns the blue channel value.
+            pub const fn b(self) -> u8 {
+                self.b
+            }
+
+            /// Returns the alpha channel value, where `0` is fully transparent and `255` is
+            /// fully opaque.
+            pub const fn a(self) -> u8 {
+                self.a
+            }
+
+            /// Discards the alpha channel, returning the opaque RGB color.
+            pub const fn to_rgb(self) -> Rgb888 {
+                Rgb888::new(self.r, self.g, self.b)
+            }
+        }
+
+        impl PixelColor for $name {
+            type Raw = RawU32;
+        }
+
+        impl From<RawU32> for $name {
+            fn from(data: RawU32) -> Self {
+                let p = data.into_inner();
+
+                Self::new(
+                    (p >> $r_shift) as u8,
+                    (p >> $g_shift) as u8,
+                    (p >> $b_shift) as u8,
+                    (p >> $a_shift) as u8,
+                )
+            }
+        }
+
+        impl From<$name> for RawU32 {
+            fn from(color: $name) -> Self {
+                RawU32::new(
+                    (color.r as u32) << $r_shift
+                        | (color.g as u32) << $g_shift
+                        | (color.b as u32) << $b_shift
+                        | (color.a as u32) << $a_shift,
+                )
+            }
+        }
+    };
+}
+
+rgba_color!(
+    /// RGBA color with 8 bits per channel, packed R-G-B-A into a 32 bit storage value.
+    ///
+    /// Unlike the opaque color types in this module, `Rgba8888` carries an additional alpha
+    /// channel. It is not itself drawable onto an opaque [`DrawTarget`], but can be composited
+    /// onto one with [`Blend`].
+    ///
+    /// [`DrawTarget`]: super::super::draw_target::DrawTarget
+    /// [`Blend`]: super::Blend
+    Rgba8888,
+    (24, 16, 8, 0)
+);
+
+rgba_color!(
+    /// RGBA color with 8 bits per channel, packed A-R-G-B into a 32 bit storage value.
+    ///
+    /// Stores the same logical red/green/blue/alpha channels as [`Rgba8888`], but with the alpha
+    /// channel occupying the most significant byte instead of the least significant one. Use this
+    /// type instead of `Rgba8888` when interoperating with formats that put alpha first, such as
+    /// the `rgb` crate's `Argb` type.
+    Argb8888,
+    (16, 8, 0, 24)
+);
+
+impl From<Rgba8888> for Argb8888 {
+    fn from(color: Rgba8888) -> Self {
+        Self::new(color.r(), color.g(), color.b(), color.a())
+    }
+}
+
+impl From<Argb8888> for Rgba8888 {
+    fn from(color: Argb8888) -> Self {
+        Self::new(color.r(), color.g(), color.b(), color.a())
+    }
+}
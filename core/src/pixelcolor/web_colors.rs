@@ -0,0 +1,58 @@
+use crate::pixelcolor::RgbColor;
+
+/// CSS web color constants.
+///
+/// This trait provides a small set of additional named colors, as defined by the CSS Color
+/// Module, that aren't already covered by the primary/secondary colors on [`RgbColor`].
+pub trait WebColors: RgbColor {
+    /// CSS `csswhitesmoke` (`#F5F5F5`)
+    const CSS_WHITE_SMOKE: Self;
+
+    /// CSS `cssgray` (`#808080`)
+    const CSS_GRAY: Self;
+
+    /// CSS `csssilver` (`#C0C0C0`)
+    const CSS_SILVER: Self;
+
+    /// CSS `cssorange` (`#FFA500`)
+    const CSS_ORANGE: Self;
+
+    /// CSS `csspurple` (`#800080`)
+    const CSS_PURPLE: Self;
+
+    /// CSS `csspink` (`#FFC0CB`)
+    const CSS_PINK: Self;
+
+    /// CSS `cssbrown` (`#A52A2A`)
+    const CSS_BROWN: Self;
+
+    /// CSS `csscoral` (`#FF7F50`)
+    const CSS_CORAL: Self;
+
+    /// CSS `csstomato` (`#FF6347`)
+    const CSS_TOMATO: Self;
+
+    /// CSS `csslimegreen` (`#32CD32`)
+    const CSS_LIME_GREEN: Self;
+
+    /// CSS `cssnavy` (`#000080`)
+    const CSS_NAVY: Self;
+
+    /// CSS `cssolive` (`#808000`)
+    const CSS_OLIVE: Self;
+}
+
+impl<C: RgbColor> WebColors for C {
+    const CSS_WHITE_SMOKE: Self = Self::new(0xF5, 0xF5, 0xF5);
+    const CSS_GRAY: Self = Self::new(0x80, 0x80, 0x80);
+    const CSS_SILVER: Self = Self::new(0xC0, 0xC0, 0xC0);
+    const CSS_ORANGE: Self = Self::new(0xFF, 0xA5, 0x00);
+    const CSS_PURPLE: Self = Self::new(0x80, 0x00, 0x80);
+    const CSS_PINK: Self = Self::new(0xFF, 0xC0, 0xCB);
+    const CSS_BROWN: Self = Self::new(0xA5, 0x2A, 0x2A);
+    const CSS_CORAL: Self = Self::new(0xFF, 0x7F, 0x50);
+    const CSS_TOMATO: Self = Self::new(0xFF, 0x63, 0x47);
+    const CSS_LIME_GREEN: Self = Self::new(0x32, 0xCD, 0x32);
+    const CSS_NAVY: Self = Self::new(0x00, 0x00, 0x80);
+    const CSS_OLIVE: Self = Self::new(0x80, 0x80, 0x00);
+}